@@ -0,0 +1,38 @@
+# term-ai bash integration
+#
+# Records each command and its exit code so `term-ai --fix` can suggest
+# a corrected command after a failure.
+#
+# Install: add this line to your ~/.bashrc
+#   source /path/to/term-ai/shell-integrations/bash/term-ai.bash
+
+_TERM_AI_STATE_DIR="${TERM_AI_STATE_DIR:-$HOME/.term-ai}"
+
+_term_ai_prompt_command() {
+    local exit_code=$?
+    local entry number command
+    entry=$(HISTTIMEFORMAT= builtin history 1)
+    number=${entry%%[^ 0-9]*}
+    number=${number// /}
+    # An empty line leaves history unchanged; don't record the previous
+    # command again
+    if [[ -z $number || $number == "$_TERM_AI_LAST_NUMBER" ]]; then
+        return $exit_code
+    fi
+    _TERM_AI_LAST_NUMBER=$number
+    command=${entry#"${entry%%[^ 0-9]*}"}
+    command=${command#"${command%%[! ]*}"}
+    # Don't record term-ai itself, or --fix would try to fix its own invocation
+    if [[ $command == term-ai* || $command == *"/term-ai"* ]]; then
+        return $exit_code
+    fi
+    mkdir -p "$_TERM_AI_STATE_DIR"
+    printf '%s\n%s\n' "$exit_code" "$command" >| "$_TERM_AI_STATE_DIR/last_command"
+    return $exit_code
+}
+
+# The first history entry is from an earlier session
+_TERM_AI_LAST_NUMBER=$(HISTTIMEFORMAT= builtin history 1)
+_TERM_AI_LAST_NUMBER=${_TERM_AI_LAST_NUMBER%%[^ 0-9]*}
+_TERM_AI_LAST_NUMBER=${_TERM_AI_LAST_NUMBER// /}
+PROMPT_COMMAND="_term_ai_prompt_command${PROMPT_COMMAND:+;$PROMPT_COMMAND}"